@@ -1,24 +1,107 @@
 use crate::{JammedNoun, NounExt};
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
 use bincode::config::{self, Configuration};
 use bincode::{encode_to_vec, Decode, Encode};
 use blake3::{Hash, Hasher};
 use bytes::Bytes;
+use k256::ecdsa::signature::{Signer, Verifier};
+use k256::ecdsa::{Signature, SigningKey, VerifyingKey};
 use nockvm::jets::cold::{Cold, Nounable};
 use nockvm::mem::NockStack;
 use nockvm::noun::Noun;
 use nockvm_macros::tas;
+use rand::RngCore;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{debug, error, warn};
 
+/// Length in bytes of the AES-256-GCM key used to encrypt checkpoints at rest.
+pub const CHECKPOINT_KEY_LEN: usize = 32;
+/// Length in bytes of the random nonce prepended to an encrypted jam.
+pub const CHECKPOINT_NONCE_LEN: usize = 12;
+/// Length in bytes of a compact secp256k1 ECDSA signature (`r || s`).
+pub const CHECKPOINT_SIGNATURE_LEN: usize = 64;
+/// Length in bytes of a SEC1-compressed secp256k1 public key.
+pub const CHECKPOINT_PUBKEY_LEN: usize = 33;
+/// Fixed chunk size used to split a jammed payload for delta checkpointing.
+pub const CHECKPOINT_CHUNK_LEN: usize = 1 << 20;
+/// Force a full checkpoint after this many consecutive deltas, so a single corrupt delta can
+/// never orphan the whole chain.
+pub const CHECKPOINT_MAX_DELTAS: u32 = 16;
+/// Absolute upper bound on a checkpoint's declared uncompressed length, checked before it is
+/// used to size a decompression buffer. A corrupt or malicious on-disk slot can declare an
+/// arbitrary length, and this module exists to survive exactly that kind of corruption, so an
+/// oversized value must surface as an error rather than abort the process via a huge allocation.
+/// This is a backstop; [`CHECKPOINT_MAX_COMPRESSION_RATIO`] is the bound that actually matters
+/// for a ring with several present slots, since this absolute ceiling alone still allows each of
+/// them to demand a multi-gigabyte buffer.
+pub const CHECKPOINT_MAX_UNCOMPRESSED_LEN: usize = 1 << 32;
+/// Upper bound on how large a declared `uncompressed_len` may be relative to the actual
+/// compressed byte count (taken after decryption, for an encrypted checkpoint), i.e. the classic
+/// decompression-bomb guard.
+/// Zstd/lz4 can exceed this on deliberately-crafted pathological input, but legitimate jammed
+/// Nock nouns never come close, so this catches a corrupt/malicious declared length long before
+/// it threatens memory even when every slot in the ring is hit at once.
+pub const CHECKPOINT_MAX_COMPRESSION_RATIO: usize = 100;
+
+/// Codec used to compress a jammed payload before it is written to disk. Jammed Nock nouns are
+/// highly compressible, so this meaningfully shrinks checkpoint files and fsync time.
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum CompressionCodec {
+    /// Jam is stored as-is.
+    #[default]
+    None,
+    /// Jam is compressed with zstd.
+    Zstd,
+    /// Jam is compressed with lz4.
+    Lz4,
+}
+
+impl CompressionCodec {
+    fn compress(self, bytes: &[u8]) -> Result<Vec<u8>, CheckpointError> {
+        match self {
+            CompressionCodec::None => Ok(bytes.to_vec()),
+            CompressionCodec::Zstd => zstd::stream::encode_all(bytes, 0)
+                .map_err(|e| CheckpointError::StoreError(e.to_string())),
+            CompressionCodec::Lz4 => Ok(lz4_flex::compress(bytes)),
+        }
+    }
+
+    fn decompress(self, bytes: &Bytes, uncompressed_len: usize) -> Result<Bytes, CheckpointError> {
+        let max_len = bytes
+            .len()
+            .saturating_mul(CHECKPOINT_MAX_COMPRESSION_RATIO)
+            .min(CHECKPOINT_MAX_UNCOMPRESSED_LEN);
+        if uncompressed_len > max_len {
+            return Err(CheckpointError::OversizedPayload {
+                declared: uncompressed_len,
+                max: max_len,
+            });
+        }
+        match self {
+            CompressionCodec::None => Ok(bytes.clone()),
+            CompressionCodec::Zstd => {
+                let mut out = Vec::with_capacity(uncompressed_len);
+                zstd::stream::copy_decode(bytes.as_ref(), &mut out)
+                    .map_err(|e| CheckpointError::StoreError(e.to_string()))?;
+                Ok(Bytes::from(out))
+            }
+            CompressionCodec::Lz4 => lz4_flex::decompress(bytes, uncompressed_len)
+                .map(Bytes::from)
+                .map_err(|e| CheckpointError::StoreError(e.to_string())),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Checkpoint {
     /// Magic bytes to identify checkpoint format
     pub magic_bytes: u64,
     /// Version of checkpoint
     pub version: u32,
-    /// The buffer that this checkpoint was saved to, either 0 or 1.
-    pub buff_index: bool,
+    /// The slot id in the checkpoint ring that this checkpoint was saved to.
+    pub buff_index: u32,
     /// Hash of the boot kernel
     pub ker_hash: Hash,
     /// Event number
@@ -43,8 +126,62 @@ impl std::fmt::Debug for Checkpoint {
 }
 
 impl Checkpoint {
-    pub fn load(stack: &mut NockStack, jam: JammedCheckpoint) -> Result<Self, CheckpointError> {
-        let cell = <Noun as NounExt>::cue_bytes(stack, &jam.jam.0)
+    /// Load a checkpoint from its jammed form. If `jam` is encrypted (see
+    /// [`JammedCheckpoint::new_encrypted`]), `key` must be supplied to decrypt it before cueing.
+    pub fn load(
+        stack: &mut NockStack,
+        jam: JammedCheckpoint,
+        key: Option<&[u8; CHECKPOINT_KEY_LEN]>,
+    ) -> Result<Self, CheckpointError> {
+        let plaintext = if jam.is_encrypted() {
+            let key = key.ok_or(CheckpointError::DecryptionFailed)?;
+            jam.decrypt(key)?
+        } else {
+            jam.codec
+                .decompress(&jam.jam.0, jam.uncompressed_len as usize)?
+        };
+
+        Self::from_plaintext(
+            stack,
+            &plaintext,
+            jam.magic_bytes,
+            jam.version,
+            jam.buff_index,
+            jam.ker_hash,
+            jam.event_num,
+        )
+    }
+
+    /// Load a checkpoint from a [`DeltaCheckpoint`], reconstructing the full jam from
+    /// `chunk_store` before cueing it.
+    pub fn load_delta<C: ChunkStore>(
+        stack: &mut NockStack,
+        delta: DeltaCheckpoint,
+        chunk_store: &C,
+    ) -> Result<Self, CheckpointError> {
+        let plaintext = delta.reconstruct(chunk_store)?;
+        Self::from_plaintext(
+            stack,
+            &plaintext,
+            delta.magic_bytes,
+            delta.version,
+            delta.buff_index,
+            delta.ker_hash,
+            delta.event_num,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn from_plaintext(
+        stack: &mut NockStack,
+        plaintext: &Bytes,
+        magic_bytes: u64,
+        version: u32,
+        buff_index: u32,
+        ker_hash: Hash,
+        event_num: u64,
+    ) -> Result<Self, CheckpointError> {
+        let cell = <Noun as NounExt>::cue_bytes(stack, plaintext)
             .map_err(|_| CheckpointError::SwordInterpreterError)?
             .as_cell()?;
 
@@ -52,11 +189,11 @@ impl Checkpoint {
         let cold = Cold::from_vecs(stack, cold_mem.0, cold_mem.1, cold_mem.2);
 
         Ok(Self {
-            magic_bytes: jam.magic_bytes,
-            version: jam.version,
-            buff_index: jam.buff_index,
-            ker_hash: jam.ker_hash,
-            event_num: jam.event_num,
+            magic_bytes,
+            version,
+            buff_index,
+            ker_hash,
+            event_num,
             ker_state: cell.head(),
             cold,
         })
@@ -69,8 +206,8 @@ pub struct JammedCheckpoint {
     pub magic_bytes: u64,
     /// Version of checkpoint
     pub version: u32,
-    /// The buffer this checkpoint was saved to, either 0 or 1
-    pub buff_index: bool,
+    /// The slot id in the checkpoint ring that this checkpoint was saved to
+    pub buff_index: u32,
     /// Hash of the boot kernel
     #[bincode(with_serde)]
     pub ker_hash: Hash,
@@ -79,8 +216,27 @@ pub struct JammedCheckpoint {
     pub checksum: Hash,
     /// Event number
     pub event_num: u64,
-    /// Jammed noun of [kernel_state cold_state]
+    /// Codec `jam` is compressed with, if any. The checksum above is always taken over the
+    /// uncompressed jam, so integrity semantics are unaffected by the codec choice.
+    pub codec: CompressionCodec,
+    /// Length of the jam before compression, used to pre-allocate the decompression buffer.
+    pub uncompressed_len: u64,
+    /// Jammed noun of [kernel_state cold_state], compressed with `codec`
     pub jam: JammedNoun,
+    /// Optional ECDSA signature over `checksum`, proving which operator produced this
+    /// checkpoint. Absent for unsigned checkpoints, which are still accepted unless the caller
+    /// requires signatures.
+    pub signature: Option<CheckpointSignature>,
+}
+
+/// A compact secp256k1 ECDSA signature over a [`JammedCheckpoint::checksum`], plus the signer's
+/// public key, so a checkpoint's authenticity can be verified against an allowlist.
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct CheckpointSignature {
+    /// 64-byte compact (`r || s`) ECDSA signature.
+    pub signature: [u8; CHECKPOINT_SIGNATURE_LEN],
+    /// 33-byte SEC1-compressed public key of the signer.
+    pub pubkey: [u8; CHECKPOINT_PUBKEY_LEN],
 }
 
 /// A structure for exporting just the kernel state, without the cold state
@@ -95,7 +251,11 @@ pub struct ExportedState {
     pub ker_hash: Hash,
     /// Event number
     pub event_num: u64,
-    /// Jammed noun of kernel_state
+    /// Codec `jam` is compressed with, if any.
+    pub codec: CompressionCodec,
+    /// Length of the jam before compression, used to pre-allocate the decompression buffer.
+    pub uncompressed_len: u64,
+    /// Jammed noun of kernel_state, compressed with `codec`
     pub jam: JammedNoun,
 }
 
@@ -106,15 +266,20 @@ impl ExportedState {
         ker_hash: Hash,
         event_num: u64,
         ker_state: &Noun,
-    ) -> Self {
+        codec: CompressionCodec,
+    ) -> Result<Self, CheckpointError> {
         let jam = JammedNoun::from_noun(stack, *ker_state);
-        Self {
+        let uncompressed_len = jam.0.len() as u64;
+        let compressed = codec.compress(&jam.0)?;
+        Ok(Self {
             magic_bytes: tas!(b"EXPJAM"),
             version,
             ker_hash,
             event_num,
-            jam,
-        }
+            codec,
+            uncompressed_len,
+            jam: JammedNoun(Bytes::from(compressed)),
+        })
     }
 
     pub fn encode(&self) -> Result<Vec<u8>, bincode::error::EncodeError> {
@@ -125,25 +290,166 @@ impl ExportedState {
 impl JammedCheckpoint {
     pub fn new(
         version: u32,
-        buff_index: bool,
+        buff_index: u32,
         ker_hash: Hash,
         event_num: u64,
         jam: JammedNoun,
-    ) -> Self {
+        codec: CompressionCodec,
+    ) -> Result<Self, CheckpointError> {
         let checksum = Self::checksum(event_num, &jam.0);
-        Self {
+        let uncompressed_len = jam.0.len() as u64;
+        let compressed = codec.compress(&jam.0)?;
+        Ok(Self {
             magic_bytes: tas!(b"CHKJAM"),
             version,
             buff_index,
             ker_hash,
             checksum,
             event_num,
-            jam,
+            codec,
+            uncompressed_len,
+            jam: JammedNoun(Bytes::from(compressed)),
+            signature: None,
+        })
+    }
+
+    /// Like [`Self::new`], but encrypts the (possibly already-compressed) jammed
+    /// `[kernel_state cold_state]` payload with AES-256-GCM under `key` before it is ever written
+    /// to disk. The checksum is still taken over the *uncompressed, unencrypted* jam, so
+    /// integrity semantics after decryption and decompression are unchanged.
+    pub fn new_encrypted(
+        version: u32,
+        buff_index: u32,
+        ker_hash: Hash,
+        event_num: u64,
+        jam: JammedNoun,
+        codec: CompressionCodec,
+        key: &[u8; CHECKPOINT_KEY_LEN],
+    ) -> Result<Self, CheckpointError> {
+        let checksum = Self::checksum(event_num, &jam.0);
+        let uncompressed_len = jam.0.len() as u64;
+        let compressed = codec.compress(&jam.0)?;
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+        let mut nonce_bytes = [0u8; CHECKPOINT_NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        let ciphertext = cipher
+            .encrypt(nonce, compressed.as_slice())
+            .map_err(|_| CheckpointError::EncryptionFailed)?;
+
+        let mut payload = Vec::with_capacity(CHECKPOINT_NONCE_LEN + ciphertext.len());
+        payload.extend_from_slice(&nonce_bytes);
+        payload.extend_from_slice(&ciphertext);
+
+        Ok(Self {
+            magic_bytes: tas!(b"CHKENC"),
+            version,
+            buff_index,
+            ker_hash,
+            checksum,
+            event_num,
+            codec,
+            uncompressed_len,
+            jam: JammedNoun(Bytes::from(payload)),
+            signature: None,
+        })
+    }
+
+    /// Sign this checkpoint's `checksum` with `signing_key`, attaching the compact signature and
+    /// the signer's compressed public key so recipients can verify authenticity with
+    /// [`Self::verify_signature`].
+    pub fn sign(&mut self, signing_key: &SigningKey) {
+        let sig: Signature = signing_key.sign(self.checksum.as_bytes());
+        let pubkey = VerifyingKey::from(signing_key)
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("compressed secp256k1 public key is always 33 bytes");
+        self.signature = Some(CheckpointSignature {
+            signature: sig.to_bytes().into(),
+            pubkey,
+        });
+    }
+
+    /// Verify this checkpoint's signature against an allowlist of trusted signer public keys.
+    /// Returns `false` if the checkpoint is unsigned, the signature doesn't verify, or the
+    /// signer's public key is not in `allowed_signers`.
+    ///
+    /// This only authenticates the *claimed* `checksum`, not the stored `jam` bytes: an
+    /// encrypted checkpoint's ciphertext can still be corrupted, independently of `checksum` and
+    /// `signature`, by anyone with filesystem write access and no keys at all. That failure mode
+    /// is caught later, at decrypt/decompress time in [`Checkpoint::load`], not here.
+    pub fn verify_signature(&self, allowed_signers: &[[u8; CHECKPOINT_PUBKEY_LEN]]) -> bool {
+        let Some(sig) = &self.signature else {
+            return false;
+        };
+        if !allowed_signers.contains(&sig.pubkey) {
+            return false;
         }
+        let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(&sig.pubkey) else {
+            return false;
+        };
+        let Ok(signature) = Signature::try_from(sig.signature.as_slice()) else {
+            return false;
+        };
+        verifying_key
+            .verify(self.checksum.as_bytes(), &signature)
+            .is_ok()
+    }
+
+    /// True if this checkpoint's `jam` holds an AES-256-GCM-encrypted payload rather than a
+    /// plain jammed noun.
+    pub fn is_encrypted(&self) -> bool {
+        self.magic_bytes == tas!(b"CHKENC")
     }
+
+    /// For a plaintext (`CHKJAM`) checkpoint, decompresses `jam` and verifies the stored checksum
+    /// against it. An encrypted (`CHKENC`) checkpoint cannot be validated without its key, so
+    /// this always returns `true` for those and the real check happens in [`Self::decrypt`].
     pub fn validate(&self) -> bool {
-        self.checksum == Self::checksum(self.event_num, &self.jam.0)
+        if self.is_encrypted() {
+            return true;
+        }
+        match self
+            .codec
+            .decompress(&self.jam.0, self.uncompressed_len as usize)
+        {
+            Ok(plain) => self.checksum == Self::checksum(self.event_num, &plain),
+            Err(_) => false,
+        }
     }
+
+    /// Decrypt and decompress `self.jam` with `key`, returning the plaintext jammed
+    /// `[kernel_state cold_state]` bytes and verifying the blake3 checksum against them. Returns
+    /// [`CheckpointError::DecryptionFailed`] if the payload is not encrypted, the key is wrong,
+    /// the AES-GCM tag fails to authenticate, or decompression fails.
+    pub fn decrypt(&self, key: &[u8; CHECKPOINT_KEY_LEN]) -> Result<Bytes, CheckpointError> {
+        if !self.is_encrypted() {
+            return Err(CheckpointError::DecryptionFailed);
+        }
+        if self.jam.0.len() < CHECKPOINT_NONCE_LEN {
+            return Err(CheckpointError::DecryptionFailed);
+        }
+        let (nonce_bytes, ciphertext) = self.jam.0.split_at(CHECKPOINT_NONCE_LEN);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+        let nonce = Nonce::from_slice(nonce_bytes);
+        let compressed = cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| CheckpointError::DecryptionFailed)?;
+
+        let plaintext = self
+            .codec
+            .decompress(&Bytes::from(compressed), self.uncompressed_len as usize)
+            .map_err(|_| CheckpointError::DecryptionFailed)?;
+
+        if self.checksum != Self::checksum(self.event_num, &plaintext) {
+            return Err(CheckpointError::DecryptionFailed);
+        }
+
+        Ok(plaintext)
+    }
+
     pub fn encode(&self) -> Result<Vec<u8>, bincode::error::EncodeError> {
         encode_to_vec(self, config::standard())
     }
@@ -157,93 +463,864 @@ impl JammedCheckpoint {
     }
 }
 
+/// Content-addressed storage for checkpoint chunks, keyed by their blake3 hash. Lets
+/// [`DeltaCheckpoint`] dedupe chunks against whatever a baseline or earlier delta already wrote,
+/// independent of where those bytes actually live.
+pub trait ChunkStore {
+    type Error: std::fmt::Display;
+
+    /// Whether a chunk with this hash has already been written.
+    fn has_chunk(&self, hash: &Hash) -> bool;
+    /// Read back a previously-written chunk.
+    fn read_chunk(&self, hash: &Hash) -> Result<Vec<u8>, Self::Error>;
+    /// Write a chunk if it is not already present.
+    fn write_chunk(&self, hash: &Hash, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A [`ChunkStore`] that persists each chunk as a file named by its hex-encoded blake3 hash.
+#[derive(Debug, Clone)]
+pub struct FileChunkStore {
+    dir: PathBuf,
+}
+
+impl FileChunkStore {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    fn chunk_path(&self, hash: &Hash) -> PathBuf {
+        self.dir.join(hash.to_hex().as_str())
+    }
+}
+
+impl ChunkStore for FileChunkStore {
+    type Error = std::io::Error;
+
+    fn has_chunk(&self, hash: &Hash) -> bool {
+        self.chunk_path(hash).exists()
+    }
+
+    fn read_chunk(&self, hash: &Hash) -> Result<Vec<u8>, Self::Error> {
+        std::fs::read(self.chunk_path(hash))
+    }
+
+    fn write_chunk(&self, hash: &Hash, bytes: &[u8]) -> Result<(), Self::Error> {
+        let path = self.chunk_path(hash);
+        if path.exists() {
+            return Ok(());
+        }
+        std::fs::write(path, bytes)
+    }
+}
+
+/// A reference to one fixed-size, content-addressed chunk of a jammed payload.
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct ChunkRef {
+    /// blake3 hash of the chunk, used as its key in a [`ChunkStore`].
+    #[bincode(with_serde)]
+    pub hash: Hash,
+    /// Byte offset of this chunk within the reconstructed jam.
+    pub offset: u64,
+    /// Length of this chunk in bytes.
+    pub len: u64,
+}
+
+/// A checkpoint expressed as a manifest of content-addressed chunks rather than a full jam,
+/// cutting write amplification for checkpoints taken after a full baseline. Every chunk not
+/// already known to the [`ChunkStore`] is written once; unchanged chunks are referenced by hash
+/// instead of being rewritten.
+#[derive(Encode, Decode, PartialEq, Debug, Clone)]
+pub struct DeltaCheckpoint {
+    /// Magic bytes to identify the delta checkpoint format
+    pub magic_bytes: u64,
+    /// Version of checkpoint
+    pub version: u32,
+    /// The slot id in the checkpoint ring that this checkpoint was saved to
+    pub buff_index: u32,
+    /// Hash of the boot kernel
+    #[bincode(with_serde)]
+    pub ker_hash: Hash,
+    /// Checksum derived from event_num and the reconstructed (uncompressed) jam
+    #[bincode(with_serde)]
+    pub checksum: Hash,
+    /// Event number
+    pub event_num: u64,
+    /// Ordered chunks making up the jammed `[kernel_state cold_state]` payload
+    pub chunks: Vec<ChunkRef>,
+}
+
+impl DeltaCheckpoint {
+    /// Split `jam` into fixed-size, blake3-keyed chunks, writing any chunk not already present
+    /// in `chunk_store` and recording the full manifest needed to reconstruct `jam`.
+    pub fn new<C: ChunkStore>(
+        chunk_store: &C,
+        version: u32,
+        buff_index: u32,
+        ker_hash: Hash,
+        event_num: u64,
+        jam: &JammedNoun,
+    ) -> Result<Self, CheckpointError> {
+        let checksum = JammedCheckpoint::checksum(event_num, &jam.0);
+
+        let mut chunks = Vec::new();
+        for (i, chunk) in jam.0.chunks(CHECKPOINT_CHUNK_LEN).enumerate() {
+            let hash = blake3::hash(chunk);
+            if !chunk_store.has_chunk(&hash) {
+                chunk_store
+                    .write_chunk(&hash, chunk)
+                    .map_err(|e| CheckpointError::StoreError(e.to_string()))?;
+            }
+            chunks.push(ChunkRef {
+                hash,
+                offset: (i * CHECKPOINT_CHUNK_LEN) as u64,
+                len: chunk.len() as u64,
+            });
+        }
+
+        Ok(Self {
+            magic_bytes: tas!(b"CHKDLT"),
+            version,
+            buff_index,
+            ker_hash,
+            checksum,
+            event_num,
+            chunks,
+        })
+    }
+
+    /// Reconstruct the full jammed payload by resolving every chunk against `chunk_store`,
+    /// returning [`CheckpointError::MissingChunk`] for the first chunk that isn't found, whose
+    /// length doesn't match the manifest's recorded `len`, or whose `offset` doesn't match where
+    /// it actually lands in the reconstructed jam (e.g. a stale file reused after a hash-space
+    /// collision, a partial write, or a reordered manifest), and
+    /// [`CheckpointError::InvalidChecksum`] if the reconstructed jam doesn't match `checksum`.
+    pub fn reconstruct<C: ChunkStore>(&self, chunk_store: &C) -> Result<Bytes, CheckpointError> {
+        let mut jam = Vec::new();
+        for chunk_ref in &self.chunks {
+            if chunk_ref.offset != jam.len() as u64 {
+                return Err(CheckpointError::MissingChunk(chunk_ref.hash));
+            }
+            if !chunk_store.has_chunk(&chunk_ref.hash) {
+                return Err(CheckpointError::MissingChunk(chunk_ref.hash));
+            }
+            let bytes = chunk_store
+                .read_chunk(&chunk_ref.hash)
+                .map_err(|e| CheckpointError::StoreError(e.to_string()))?;
+            if bytes.len() as u64 != chunk_ref.len {
+                return Err(CheckpointError::MissingChunk(chunk_ref.hash));
+            }
+            jam.extend_from_slice(&bytes);
+        }
+
+        let jam = Bytes::from(jam);
+        if self.checksum != JammedCheckpoint::checksum(self.event_num, &jam) {
+            return Err(CheckpointError::InvalidChecksum(format!(
+                "delta checkpoint at event {}",
+                self.event_num
+            )));
+        }
+
+        Ok(jam)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, bincode::error::EncodeError> {
+        encode_to_vec(self, config::standard())
+    }
+}
+
+/// Either format a ring slot may hold, as dispatched by [`JamPaths::decode_jam`] on the on-disk
+/// magic bytes: a full jam, or a chunk manifest to be resolved against a [`ChunkStore`].
+#[derive(Debug)]
+pub enum StoredCheckpoint {
+    Full(JammedCheckpoint),
+    Delta(DeltaCheckpoint),
+}
+
+impl StoredCheckpoint {
+    pub fn event_num(&self) -> u64 {
+        match self {
+            StoredCheckpoint::Full(c) => c.event_num,
+            StoredCheckpoint::Delta(c) => c.event_num,
+        }
+    }
+
+    pub fn buff_index(&self) -> u32 {
+        match self {
+            StoredCheckpoint::Full(c) => c.buff_index,
+            StoredCheckpoint::Delta(c) => c.buff_index,
+        }
+    }
+
+    pub fn checksum(&self) -> Hash {
+        match self {
+            StoredCheckpoint::Full(c) => c.checksum,
+            StoredCheckpoint::Delta(c) => c.checksum,
+        }
+    }
+
+    fn set_buff_index(&mut self, slot: u32) {
+        match self {
+            StoredCheckpoint::Full(c) => c.buff_index = slot,
+            StoredCheckpoint::Delta(c) => c.buff_index = slot,
+        }
+    }
+
+    /// Whether this candidate satisfies `policy`. A [`DeltaCheckpoint`] carries no signature of
+    /// its own, so it is treated as unsigned: acceptable under [`SignaturePolicy::Ignore`] or
+    /// [`SignaturePolicy::VerifyIfPresent`], rejected under [`SignaturePolicy::Require`].
+    fn satisfies_signature_policy(&self, policy: &SignaturePolicy) -> bool {
+        match self {
+            StoredCheckpoint::Full(c) => policy.check(c),
+            StoredCheckpoint::Delta(_) => !matches!(policy, SignaturePolicy::Require { .. }),
+        }
+    }
+}
+
+/// Tracks how many delta checkpoints have been written since the last full one, so callers know
+/// when to force a full checkpoint instead: every [`CHECKPOINT_MAX_DELTAS`] deltas, or whenever
+/// there is no baseline yet. A single corrupt delta can then never orphan the whole chain.
+///
+/// This module owns no checkpoint-writing entry point (there is no `JamPaths::write_checkpoint`
+/// to wire this into), so the caller that decides when to write a [`JammedCheckpoint`] vs. a
+/// [`DeltaCheckpoint`] owns the `DeltaPolicy` instance too: call [`Self::should_write_full`]
+/// before each write to decide the format, and [`Self::record_full`]/[`Self::record_delta`]
+/// after, to actually enforce the threshold.
+#[derive(Debug, Clone, Default)]
+pub struct DeltaPolicy {
+    deltas_since_full: u32,
+    has_baseline: bool,
+}
+
+impl DeltaPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn should_write_full(&self) -> bool {
+        !self.has_baseline || self.deltas_since_full >= CHECKPOINT_MAX_DELTAS
+    }
+
+    pub fn record_full(&mut self) {
+        self.has_baseline = true;
+        self.deltas_since_full = 0;
+    }
+
+    pub fn record_delta(&mut self) {
+        self.deltas_since_full += 1;
+    }
+}
+
 #[derive(Error, Debug)]
-pub enum CheckpointError<'a> {
+pub enum CheckpointError {
     #[error("IO error: {0}")]
     IOError(#[from] std::io::Error),
     #[error("Bincode error: {0}")]
     DecodeError(#[from] bincode::error::DecodeError),
     #[error("Invalid checksum at {0}")]
-    InvalidChecksum(&'a PathBuf),
+    InvalidChecksum(String),
     #[error("Sword noun error: {0}")]
     SwordNounError(#[from] nockvm::noun::Error),
     #[error("Sword cold error: {0}")]
     FromNounError(#[from] nockvm::jets::cold::FromNounError),
-    #[error("Both checkpoints failed: {0}, {1}")]
-    BothCheckpointsFailed(Box<CheckpointError<'a>>, Box<CheckpointError<'a>>),
+    #[error("All checkpoint slots failed: {0:?}")]
+    AllSlotsFailed(Vec<CheckpointError>),
     #[error("Sword interpreter error")]
     SwordInterpreterError,
+    #[error("Failed to encrypt checkpoint jam")]
+    EncryptionFailed,
+    #[error("Failed to decrypt checkpoint jam: wrong key, missing key, or corrupt ciphertext")]
+    DecryptionFailed,
+    #[error("Checkpoint signature is missing or does not verify against the allowed signers")]
+    InvalidSignature,
+    #[error("Checkpoint store error: {0}")]
+    StoreError(String),
+    #[error("Missing chunk: {0}")]
+    MissingChunk(Hash),
+    #[error("Declared uncompressed length {declared} exceeds the maximum of {max} bytes")]
+    OversizedPayload { declared: usize, max: usize },
+}
+
+/// Policy for verifying a checkpoint's signature on load. Every variant that verifies signatures
+/// carries its own allowlist, so requiring signatures always comes with keys to verify against.
+#[derive(Debug, Clone, Copy)]
+pub enum SignaturePolicy<'a> {
+    /// Don't check signatures at all; accept signed and unsigned checkpoints alike.
+    Ignore,
+    /// Accept unsigned checkpoints, but any signed checkpoint must verify against
+    /// `allowed_signers`.
+    VerifyIfPresent {
+        allowed_signers: &'a [[u8; CHECKPOINT_PUBKEY_LEN]],
+    },
+    /// Reject unsigned checkpoints; every checkpoint must verify against `allowed_signers`.
+    Require {
+        allowed_signers: &'a [[u8; CHECKPOINT_PUBKEY_LEN]],
+    },
+}
+
+impl SignaturePolicy<'_> {
+    /// Whether `candidate`'s signature (or lack of one) satisfies this policy.
+    fn check(&self, candidate: &JammedCheckpoint) -> bool {
+        match (self, &candidate.signature) {
+            (SignaturePolicy::Ignore, _) => true,
+            (SignaturePolicy::VerifyIfPresent { .. }, None) => true,
+            (SignaturePolicy::VerifyIfPresent { allowed_signers }, Some(_)) => {
+                candidate.verify_signature(allowed_signers)
+            }
+            (SignaturePolicy::Require { .. }, None) => false,
+            (SignaturePolicy::Require { allowed_signers }, Some(_)) => {
+                candidate.verify_signature(allowed_signers)
+            }
+        }
+    }
+}
+
+/// Pluggable persistence for jammed checkpoint slots. `JamPaths` is generic over this trait so
+/// operators can plug in object storage or remote replication backends without touching the
+/// decode/validate/cue logic in [`JamPaths::decode_jam`]/[`JamPaths::load_checkpoint`].
+pub trait CheckpointStore {
+    type Error: std::fmt::Display;
+
+    /// Read the raw (still bincode-encoded) bytes of `slot`.
+    fn read_slot(&self, slot: u32) -> Result<Vec<u8>, Self::Error>;
+    /// Overwrite `slot` with `bytes`.
+    fn write_slot(&self, slot: u32, bytes: &[u8]) -> Result<(), Self::Error>;
+    /// Which slots currently hold a checkpoint, indexed the same as `read_slot`/`write_slot`.
+    fn slots_present(&self) -> Vec<bool>;
+    /// Mark `slot` as the most recently written checkpoint. A no-op for backends where
+    /// `write_slot` is already durable and self-describing.
+    fn commit(&self, slot: u32) -> Result<(), Self::Error>;
 }
 
+/// A [`CheckpointStore`] backed by the `{slot}.chkjam` files in a directory, reproducing the
+/// on-disk layout `JamPaths` has always used.
 #[derive(Debug, Clone)]
-pub struct JamPaths(pub PathBuf, pub PathBuf);
+pub struct FileStore {
+    dir: PathBuf,
+    slots: u32,
+}
 
-impl JamPaths {
-    pub fn new(dir: &Path) -> Self {
-        let path_0 = dir.join("0.chkjam");
-        let path_1 = dir.join("1.chkjam");
-        Self(path_0, path_1)
+impl FileStore {
+    /// A store over `slots` rotating `{0..slots}.chkjam` files in `dir`.
+    pub fn new(dir: &Path, slots: u32) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            slots,
+        }
+    }
+
+    fn slot_path(&self, slot: u32) -> PathBuf {
+        self.dir.join(format!("{slot}.chkjam"))
+    }
+}
+
+impl CheckpointStore for FileStore {
+    type Error = std::io::Error;
+
+    fn read_slot(&self, slot: u32) -> Result<Vec<u8>, Self::Error> {
+        std::fs::read(self.slot_path(slot))
+    }
+
+    fn write_slot(&self, slot: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        std::fs::write(self.slot_path(slot), bytes)
+    }
+
+    fn slots_present(&self) -> Vec<bool> {
+        (0..self.slots)
+            .map(|slot| self.slot_path(slot).exists())
+            .collect()
+    }
+
+    fn commit(&self, _slot: u32) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JamPaths<S: CheckpointStore = FileStore> {
+    store: S,
+}
+
+impl JamPaths<FileStore> {
+    /// A `JamPaths` over a ring of `k` rotating `{0..k}.chkjam` files in `dir`. `k` replaces the
+    /// old hardcoded two-buffer layout, so operators can keep a deeper window of recoverable
+    /// checkpoints for rollback or audit.
+    pub fn new(dir: &Path, k: u32) -> Self {
+        Self {
+            store: FileStore::new(dir, k),
+        }
+    }
+
+    /// List every checkpoint in the ring as `(slot, event_num, checksum)`, skipping slots that
+    /// are empty or fail to decode. Useful for operator tooling that inspects what's
+    /// recoverable without loading a checkpoint into a [`NockStack`].
+    pub fn list(&self) -> Vec<(u32, u64, Hash)> {
+        self.store
+            .slots_present()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(slot, present)| present.then_some(slot as u32))
+            .filter_map(|slot| {
+                self.decode_jam(slot)
+                    .ok()
+                    .map(|c| (slot, c.event_num(), c.checksum()))
+            })
+            .collect()
+    }
+
+    /// Enforce "keep newest `keep` by event_num": decode every present slot and erase any whose
+    /// event number falls outside the top `keep`, freeing space for deeper retention windows
+    /// without growing the ring itself.
+    pub fn prune(&self, keep: usize) -> Result<(), CheckpointError> {
+        let mut entries = self.list();
+        entries.sort_by(|a, b| b.1.cmp(&a.1));
+
+        for (slot, _, _) in entries.into_iter().skip(keep) {
+            std::fs::remove_file(self.store.slot_path(slot))
+                .map_err(|e| CheckpointError::StoreError(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: CheckpointStore> JamPaths<S> {
+    /// A `JamPaths` over a caller-supplied [`CheckpointStore`] backend.
+    pub fn with_store(store: S) -> Self {
+        Self { store }
     }
 
     pub fn checkpoint_exists(&self) -> bool {
-        self.0.exists() || self.1.exists()
+        self.store.slots_present().iter().any(|present| *present)
     }
 
-    // TODO return checkpoint and which buffer is being loaded so we can set the buffer toggle
-    pub fn load_checkpoint<'a>(
-        &'a self,
-        stack: &'a mut NockStack,
-    ) -> Result<Checkpoint, CheckpointError<'a>> {
-        let (chk_0, chk_1) = [&self.0, &self.1].map(Self::decode_jam).into();
+    /// Load the newest checkpoint across all present slots that actually loads successfully.
+    ///
+    /// `key` decrypts an encrypted jam, if present. `signature_policy` governs whether a
+    /// checkpoint's signature (or lack of one) is acceptable; see [`SignaturePolicy`].
+    /// `chunk_store` resolves a slot holding a [`DeltaCheckpoint`], if the ring has one; pass
+    /// `None` (with a turbofish, e.g. `load_checkpoint::<FileChunkStore>(...)`) for a ring known
+    /// to only ever hold full checkpoints.
+    ///
+    /// Slots are tried in descending `event_num` order. A candidate that decodes and passes
+    /// `signature_policy` can still fail *deep* validation -- AES-GCM decrypt+checksum for an
+    /// encrypted [`JammedCheckpoint`], or chunk reconstruction for a [`DeltaCheckpoint`] -- and
+    /// that must not be fatal either: ciphertext corruption or a missing chunk in the newest slot
+    /// falls back to the next-best slot instead of failing the whole load. Only erroring when
+    /// every slot fails every check is the entire point of keeping a K-way retention ring.
+    pub fn load_checkpoint<C: ChunkStore>(
+        &self,
+        stack: &mut NockStack,
+        key: Option<&[u8; CHECKPOINT_KEY_LEN]>,
+        signature_policy: SignaturePolicy,
+        chunk_store: Option<&C>,
+    ) -> Result<Checkpoint, CheckpointError> {
+        let mut candidates = Vec::new();
+        let mut errors = Vec::new();
 
-        match (chk_0, chk_1) {
-            (Ok(a), Ok(b)) => {
-                let chosen = if a.event_num > b.event_num {
-                    debug!(
-                        "Loading checkpoint at: {}, checksum: {}",
-                        self.0.display(),
-                        a.checksum
-                    );
-                    a
-                } else {
+        for (slot, present) in self.store.slots_present().into_iter().enumerate() {
+            if !present {
+                continue;
+            }
+            match self.decode_jam(slot as u32) {
+                Ok(mut candidate) => {
                     debug!(
-                        "Loading checkpoint at: {}, checksum: {}",
-                        self.1.display(),
-                        b.checksum
+                        "Decoded checkpoint at slot {slot}, checksum: {}",
+                        candidate.checksum()
                     );
-                    b
-                };
-                Checkpoint::load(stack, chosen)
+                    // The slot we actually read from is authoritative, regardless of whatever
+                    // buff_index was embedded in the jam when it was written.
+                    candidate.set_buff_index(slot as u32);
+                    // A candidate must pass the signature policy to even be considered: an
+                    // older, properly signed checkpoint in another slot should win over a
+                    // newer one that fails verification, not take the whole load down.
+                    if !candidate.satisfies_signature_policy(&signature_policy) {
+                        warn!("Slot {slot} rejected by signature policy");
+                        errors.push(CheckpointError::InvalidSignature);
+                        continue;
+                    }
+                    candidates.push(candidate);
+                }
+                Err(e) => {
+                    warn!("Slot {slot} failed to decode: {e}");
+                    errors.push(e);
+                }
             }
-            (Ok(c), Err(e)) | (Err(e), Ok(c)) => {
-                warn!("{e}");
-                debug!("Loading checkpoint, checksum: {}", c.checksum);
-                Checkpoint::load(stack, c)
-            }
-            (Err(e1), Err(e2)) => {
-                error!("{e1}");
-                error!("{e2}");
-                // TODO: Why is this a panic?
-                // panic!("Error loading both checkpoints");
-                Err(CheckpointError::BothCheckpointsFailed(
-                    Box::new(e1),
-                    Box::new(e2),
-                ))
+        }
+
+        candidates.sort_by(|a, b| b.event_num().cmp(&a.event_num()));
+
+        for candidate in candidates {
+            let slot = candidate.buff_index();
+            let result = match candidate {
+                StoredCheckpoint::Full(jam) => Checkpoint::load(stack, jam, key),
+                StoredCheckpoint::Delta(delta) => match chunk_store {
+                    Some(chunk_store) => Checkpoint::load_delta(stack, delta, chunk_store),
+                    None => Err(CheckpointError::StoreError(
+                        "slot holds a delta checkpoint but no chunk store was supplied".to_string(),
+                    )),
+                },
+            };
+            match result {
+                Ok(checkpoint) => return Ok(checkpoint),
+                Err(e) => {
+                    warn!("Slot {slot} failed deep validation: {e}");
+                    errors.push(e);
+                }
             }
         }
+
+        errors.iter().for_each(|e| error!("{e}"));
+        // TODO: Why is this a panic?
+        // panic!("Error loading all checkpoints");
+        Err(CheckpointError::AllSlotsFailed(errors))
     }
 
-    pub fn decode_jam(jam_path: &PathBuf) -> Result<JammedCheckpoint, CheckpointError> {
-        let jam: Vec<u8> = std::fs::read(jam_path.as_path())?;
+    /// Decode and checksum-validate the jam in `slot`, dispatching on the on-disk magic bytes to
+    /// either a full [`JammedCheckpoint`] or a [`DeltaCheckpoint`] manifest. Note that this does
+    /// not decrypt an encrypted jam (see [`JammedCheckpoint::decrypt`]): its checksum covers the
+    /// plaintext jam, so it is not checked here but later in [`Checkpoint::load`], which takes
+    /// the decryption key. A [`DeltaCheckpoint`]'s checksum
+    /// can't be verified until its chunks are resolved, so that check happens later in
+    /// [`Checkpoint::load_delta`] instead.
+    pub fn decode_jam(&self, slot: u32) -> Result<StoredCheckpoint, CheckpointError> {
+        let jam: Vec<u8> = self
+            .store
+            .read_slot(slot)
+            .map_err(|e| CheckpointError::StoreError(e.to_string()))?;
 
         let config = bincode::config::standard();
+
+        if peek_magic_bytes(&jam)? == tas!(b"CHKDLT") {
+            let (delta, _) =
+                bincode::decode_from_slice::<DeltaCheckpoint, Configuration>(&jam, config)?;
+            return Ok(StoredCheckpoint::Delta(delta));
+        }
+
         let (checkpoint, _) =
             bincode::decode_from_slice::<JammedCheckpoint, Configuration>(&jam, config)?;
 
         if checkpoint.validate() {
-            Ok(checkpoint)
+            Ok(StoredCheckpoint::Full(checkpoint))
         } else {
-            Err(CheckpointError::InvalidChecksum(jam_path))
+            Err(CheckpointError::InvalidChecksum(format!("slot {slot}")))
+        }
+    }
+}
+
+/// Every on-disk checkpoint format ([`JammedCheckpoint`], [`DeltaCheckpoint`]) encodes
+/// `magic_bytes` as its first field, so it can be read without knowing which format the rest of
+/// the bytes are in.
+fn peek_magic_bytes(bytes: &[u8]) -> Result<u64, CheckpointError> {
+    #[derive(Decode)]
+    struct Magic {
+        magic_bytes: u64,
+    }
+
+    let (Magic { magic_bytes }, _) =
+        bincode::decode_from_slice::<Magic, Configuration>(bytes, bincode::config::standard())?;
+    Ok(magic_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    fn sample_jam(bytes: &[u8]) -> JammedNoun {
+        JammedNoun(Bytes::from(bytes.to_vec()))
+    }
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_slice(&[seed; 32]).expect("valid scalar")
+    }
+
+    fn pubkey_of(signing_key: &SigningKey) -> [u8; CHECKPOINT_PUBKEY_LEN] {
+        VerifyingKey::from(signing_key)
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("compressed secp256k1 public key is always 33 bytes")
+    }
+
+    #[test]
+    fn plaintext_round_trip_validates() {
+        let checkpoint = JammedCheckpoint::new(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"hello checkpoint"),
+            CompressionCodec::Zstd,
+        )
+        .expect("encode");
+        assert!(checkpoint.validate());
+    }
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let key = [7u8; CHECKPOINT_KEY_LEN];
+        let checkpoint = JammedCheckpoint::new_encrypted(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"secret kernel state"),
+            CompressionCodec::None,
+            &key,
+        )
+        .expect("encrypt");
+
+        assert!(checkpoint.is_encrypted());
+        let plaintext = checkpoint.decrypt(&key).expect("decrypt");
+        assert_eq!(plaintext.as_ref(), b"secret kernel state");
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_key() {
+        let key = [7u8; CHECKPOINT_KEY_LEN];
+        let wrong_key = [9u8; CHECKPOINT_KEY_LEN];
+        let checkpoint = JammedCheckpoint::new_encrypted(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"secret kernel state"),
+            CompressionCodec::None,
+            &key,
+        )
+        .expect("encrypt");
+
+        assert!(matches!(
+            checkpoint.decrypt(&wrong_key),
+            Err(CheckpointError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn decrypt_rejects_tampered_ciphertext() {
+        let key = [7u8; CHECKPOINT_KEY_LEN];
+        let mut checkpoint = JammedCheckpoint::new_encrypted(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"secret kernel state"),
+            CompressionCodec::None,
+            &key,
+        )
+        .expect("encrypt");
+
+        let mut tampered = checkpoint.jam.0.to_vec();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xFF;
+        checkpoint.jam = JammedNoun(Bytes::from(tampered));
+
+        assert!(matches!(
+            checkpoint.decrypt(&key),
+            Err(CheckpointError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let mut checkpoint = JammedCheckpoint::new(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"kernel state"),
+            CompressionCodec::None,
+        )
+        .expect("encode");
+
+        let signing_key = signing_key(1);
+        checkpoint.sign(&signing_key);
+
+        assert!(checkpoint.verify_signature(&[pubkey_of(&signing_key)]));
+    }
+
+    #[test]
+    fn verify_rejects_signer_not_in_allowlist() {
+        let mut checkpoint = JammedCheckpoint::new(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"kernel state"),
+            CompressionCodec::None,
+        )
+        .expect("encode");
+
+        checkpoint.sign(&signing_key(1));
+
+        assert!(!checkpoint.verify_signature(&[pubkey_of(&signing_key(2))]));
+    }
+
+    #[test]
+    fn verify_rejects_tampered_checksum() {
+        let mut checkpoint = JammedCheckpoint::new(
+            1,
+            0,
+            blake3::hash(b"kernel"),
+            42,
+            sample_jam(b"kernel state"),
+            CompressionCodec::None,
+        )
+        .expect("encode");
+
+        let signing_key = signing_key(1);
+        checkpoint.sign(&signing_key);
+        checkpoint.checksum = blake3::hash(b"forged checksum");
+
+        assert!(!checkpoint.verify_signature(&[pubkey_of(&signing_key)]));
+    }
+
+    #[derive(Default)]
+    struct InMemoryChunkStore {
+        chunks: Mutex<HashMap<Hash, Vec<u8>>>,
+    }
+
+    impl ChunkStore for InMemoryChunkStore {
+        type Error = std::convert::Infallible;
+
+        fn has_chunk(&self, hash: &Hash) -> bool {
+            self.chunks.lock().unwrap().contains_key(hash)
+        }
+
+        fn read_chunk(&self, hash: &Hash) -> Result<Vec<u8>, Self::Error> {
+            Ok(self
+                .chunks
+                .lock()
+                .unwrap()
+                .get(hash)
+                .cloned()
+                .unwrap_or_default())
         }
+
+        fn write_chunk(&self, hash: &Hash, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.chunks.lock().unwrap().insert(*hash, bytes.to_vec());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn delta_checkpoint_round_trip() {
+        let store = InMemoryChunkStore::default();
+        let payload = vec![7u8; (CHECKPOINT_CHUNK_LEN * 2) + 123];
+        let jam = JammedNoun(Bytes::from(payload.clone()));
+
+        let delta =
+            DeltaCheckpoint::new(&store, 1, 0, blake3::hash(b"kernel"), 42, &jam).expect("delta");
+
+        let reconstructed = delta.reconstruct(&store).expect("reconstruct");
+        assert_eq!(reconstructed.as_ref(), payload.as_slice());
+    }
+
+    #[test]
+    fn delta_checkpoint_reconstruct_fails_on_missing_chunk() {
+        let store = InMemoryChunkStore::default();
+        let payload = vec![3u8; CHECKPOINT_CHUNK_LEN + 1];
+        let jam = JammedNoun(Bytes::from(payload));
+
+        let delta =
+            DeltaCheckpoint::new(&store, 1, 0, blake3::hash(b"kernel"), 42, &jam).expect("delta");
+
+        let empty_store = InMemoryChunkStore::default();
+        assert!(matches!(
+            delta.reconstruct(&empty_store),
+            Err(CheckpointError::MissingChunk(_))
+        ));
+    }
+
+    #[test]
+    fn delta_checkpoint_reconstruct_fails_on_truncated_chunk() {
+        let store = InMemoryChunkStore::default();
+        let payload = vec![5u8; CHECKPOINT_CHUNK_LEN + 1];
+        let jam = JammedNoun(Bytes::from(payload));
+
+        let delta =
+            DeltaCheckpoint::new(&store, 1, 0, blake3::hash(b"kernel"), 42, &jam).expect("delta");
+
+        // Overwrite the first chunk with something shorter than its manifest `len`, simulating a
+        // partial write or a stale file reused after a hash-space collision.
+        let first_hash = delta.chunks[0].hash;
+        store
+            .write_chunk(&first_hash, &[5u8; 4])
+            .expect("overwrite chunk");
+
+        assert!(matches!(
+            delta.reconstruct(&store),
+            Err(CheckpointError::MissingChunk(_))
+        ));
+    }
+
+    #[test]
+    fn delta_policy_forces_full_without_baseline_and_past_threshold() {
+        let mut policy = DeltaPolicy::new();
+        assert!(policy.should_write_full(), "no baseline yet");
+
+        policy.record_full();
+        assert!(!policy.should_write_full(), "fresh baseline");
+
+        for _ in 0..CHECKPOINT_MAX_DELTAS - 1 {
+            policy.record_delta();
+            assert!(!policy.should_write_full(), "under the delta threshold");
+        }
+
+        policy.record_delta();
+        assert!(policy.should_write_full(), "hit the delta threshold");
+
+        policy.record_full();
+        assert!(
+            !policy.should_write_full(),
+            "threshold resets on a new baseline"
+        );
+    }
+
+    #[test]
+    fn decompress_rejects_oversized_uncompressed_len() {
+        let result = CompressionCodec::Zstd.decompress(&Bytes::from_static(b"x"), usize::MAX);
+        assert!(matches!(
+            result,
+            Err(CheckpointError::OversizedPayload { .. })
+        ));
+    }
+
+    #[test]
+    fn prune_keeps_newest_k_by_event_num() {
+        let dir = std::env::temp_dir().join(format!(
+            "nockapp_checkpoint_prune_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+
+        let write_store = FileStore::new(&dir, 4);
+        for (slot, event_num) in [(0u32, 1u64), (1, 2), (2, 3), (3, 4)] {
+            let checkpoint = JammedCheckpoint::new(
+                1,
+                slot,
+                blake3::hash(b"kernel"),
+                event_num,
+                sample_jam(b"jam"),
+                CompressionCodec::None,
+            )
+            .expect("encode");
+            write_store
+                .write_slot(slot, &checkpoint.encode().expect("bincode encode"))
+                .expect("write slot");
+        }
+
+        let jam_paths = JamPaths::new(&dir, 4);
+        assert_eq!(jam_paths.list().len(), 4);
+
+        jam_paths.prune(2).expect("prune");
+
+        let mut remaining: Vec<u64> = jam_paths.list().into_iter().map(|(_, e, _)| e).collect();
+        remaining.sort();
+        assert_eq!(remaining, vec![3, 4]);
+
+        std::fs::remove_dir_all(&dir).ok();
     }
 }